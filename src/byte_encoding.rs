@@ -0,0 +1,62 @@
+use minimization_error::MinimizationError;
+
+/**
+ * Appends a string to the given bytes, prefixed by its length
+ * @param bytes the bytes to append to
+ * @param value the string to append
+ */
+pub fn write_string(bytes: &mut Vec<u8>, value: &str) {
+  bytes.extend_from_slice(&(value.len() as u32).to_le_bytes());
+  bytes.extend_from_slice(value.as_bytes());
+}
+
+/**
+ * Reads the given number of bytes, advancing the position past them
+ * @param bytes the bytes to read from
+ * @param position the position of the first byte to read
+ * @param length the number of bytes to read
+ * @return the bytes that were read
+ * @throws error if there are not enough bytes left
+ */
+pub fn read_bytes<'a>(
+  bytes: &'a [u8],
+  position: &mut usize,
+  length: usize,
+) -> Result<&'a [u8], MinimizationError> {
+  if bytes.len() - *position < length {
+    return Err(MinimizationError::InvalidEncoding {
+      position: bytes.len(),
+    });
+  }
+
+  let read = &bytes[*position..*position + length];
+  *position += length;
+  Ok(read)
+}
+
+/**
+ * Reads a little endian 32-bit integer, advancing the position past it
+ * @param bytes the bytes to read from
+ * @param position the position of the integer
+ * @return the integer that was read
+ * @throws error if there are not enough bytes left
+ */
+pub fn read_u32(bytes: &[u8], position: &mut usize) -> Result<u32, MinimizationError> {
+  let read = read_bytes(bytes, position, 4)?;
+  Ok(u32::from_le_bytes([read[0], read[1], read[2], read[3]]))
+}
+
+/**
+ * Reads a length prefixed string, advancing the position past it
+ * @param bytes the bytes to read from
+ * @param position the position of the length of the string
+ * @return the string that was read
+ * @throws error if there are not enough bytes left or the string is not valid UTF-8
+ */
+pub fn read_string(bytes: &[u8], position: &mut usize) -> Result<String, MinimizationError> {
+  let length = read_u32(bytes, position)? as usize;
+  let start = *position;
+  let read = read_bytes(bytes, position, length)?;
+  String::from_utf8(read.to_vec())
+    .map_err(|_| MinimizationError::InvalidEncoding { position: start })
+}
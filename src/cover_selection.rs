@@ -0,0 +1,234 @@
+use product_term::ProductTerm;
+use std::collections::BTreeSet;
+
+/**
+ * Struct that selects a minimum cost cover of a set of minterms out of candidate product terms.
+ * The cost of a cover is primarily its number of product terms and secondarily the sum of the
+ * costs of its product terms. The selection is a branch and bound search that always branches on
+ * the uncovered minterm with the fewest candidates and prunes using the independent set bound
+ * (uncovered minterms that share no candidate each need a distinct product term).
+ * The search is exponential in the worst case.
+ */
+pub struct CoverSelection {
+  rows: Vec<Vec<usize>>,
+  columns: Vec<Vec<usize>>,
+  costs: Vec<f64>,
+  best: Option<(usize, f64, Vec<usize>)>,
+}
+
+impl CoverSelection {
+  /**
+   * Creates a new cover selection
+   * @param candidates product terms that can be selected in the cover
+   * @param minterms the minterms that must be covered
+   * @param variable_order the variables that appear in the product terms
+   * @param cube_cost the cost of a single product term, used to break ties between covers of equal size
+   * @return a new cover selection over the given candidates
+   */
+  pub fn new(
+    candidates: &[ProductTerm],
+    minterms: &[u64],
+    variable_order: &[String],
+    cube_cost: &dyn Fn(&ProductTerm) -> f64,
+  ) -> CoverSelection {
+    let mut rows = vec![Vec::new(); minterms.len()];
+    let mut columns = vec![Vec::new(); candidates.len()];
+    for (column, candidate) in candidates.iter().enumerate() {
+      for (row, minterm) in minterms.iter().enumerate() {
+        if candidate.covers_minterm(*minterm, variable_order) {
+          rows[row].push(column);
+          columns[column].push(row);
+        }
+      }
+    }
+    for row in &mut rows {
+      row.sort_by(|first, second| columns[*second].len().cmp(&columns[*first].len()));
+    }
+
+    CoverSelection {
+      rows,
+      columns,
+      costs: candidates.iter().map(cube_cost).collect(),
+      best: None,
+    }
+  }
+
+  /**
+   * Checks if every minterm is covered by at least one candidate
+   * @return true if a cover exists; false otherwise
+   */
+  pub fn is_coverable(&self) -> bool {
+    self.rows.iter().all(|row| !row.is_empty())
+  }
+
+  /**
+   * Searches for the minimum cost cover
+   * @return the indices of the candidates in the minimum cover; None if the minterms cannot be covered
+   */
+  pub fn select(&mut self) -> Option<Vec<usize>> {
+    if !self.is_coverable() {
+      return None;
+    }
+
+    let mut covered = vec![0; self.rows.len()];
+    let mut chosen = Vec::new();
+    self.search(&mut covered, &mut chosen, 0.0);
+
+    self.best.take().map(|(_, _, cover)| cover)
+  }
+
+  /**
+   * Searches for the size of the minimum cover. Covers of equal size are not compared by cost, so
+   * the search prunes as soon as it cannot find a smaller cover.
+   * @return the number of candidates in the minimum cover; None if the minterms cannot be covered
+   */
+  pub fn minimum_size(&mut self) -> Option<usize> {
+    for cost in &mut self.costs {
+      *cost = 0.0;
+    }
+
+    self.select().map(|cover| cover.len())
+  }
+
+  /**
+   * Searches for all the covers of minimum size, regardless of their costs
+   * @return vector containing the sorted indices of the candidates of every minimum cover, in ascending order; empty if the minterms cannot be covered
+   */
+  pub fn all_minimum(&mut self) -> Vec<Vec<usize>> {
+    let size = match self.minimum_size() {
+      Some(size) => size,
+      None => return Vec::new(),
+    };
+
+    let mut covered = vec![0; self.rows.len()];
+    let mut chosen = Vec::new();
+    let mut covers = BTreeSet::new();
+    self.enumerate(&mut covered, &mut chosen, size, &mut covers);
+
+    covers.into_iter().collect()
+  }
+
+  /**
+   * Explores the covers that extend the currently chosen candidates
+   * @param covered number of chosen candidates covering each minterm
+   * @param chosen the currently chosen candidates
+   * @param cost the summed cost of the currently chosen candidates
+   */
+  fn search(&mut self, covered: &mut Vec<usize>, chosen: &mut Vec<usize>, cost: f64) {
+    let uncovered_row = (0..self.rows.len())
+      .filter(|row| covered[*row] == 0)
+      .min_by_key(|row| self.rows[*row].len());
+    let row = match uncovered_row {
+      Some(row) => row,
+      None => {
+        if self.is_better(chosen.len(), cost) {
+          self.best = Some((chosen.len(), cost, chosen.clone()));
+        }
+        return;
+      }
+    };
+
+    if let Some((best_size, best_cost, _)) = &self.best {
+      let bound = chosen.len() + self.lower_bound(covered);
+      if bound > *best_size || bound == *best_size && cost >= *best_cost {
+        return;
+      }
+    }
+
+    for column in self.rows[row].clone() {
+      chosen.push(column);
+      for covered_row in &self.columns[column] {
+        covered[*covered_row] += 1;
+      }
+      let column_cost = self.costs[column];
+      self.search(covered, chosen, cost + column_cost);
+      for covered_row in &self.columns[column] {
+        covered[*covered_row] -= 1;
+      }
+      chosen.pop();
+    }
+  }
+
+  /**
+   * Collects the covers of the given size that extend the currently chosen candidates
+   * @param covered number of chosen candidates covering each minterm
+   * @param chosen the currently chosen candidates
+   * @param size the number of candidates of a minimum cover
+   * @param covers set to add the sorted indices of the candidates of every found cover to
+   */
+  fn enumerate(
+    &self,
+    covered: &mut Vec<usize>,
+    chosen: &mut Vec<usize>,
+    size: usize,
+    covers: &mut BTreeSet<Vec<usize>>,
+  ) {
+    let uncovered_row = (0..self.rows.len())
+      .filter(|row| covered[*row] == 0)
+      .min_by_key(|row| self.rows[*row].len());
+    let row = match uncovered_row {
+      Some(row) => row,
+      None => {
+        let mut cover = chosen.clone();
+        cover.sort();
+        covers.insert(cover);
+        return;
+      }
+    };
+    if chosen.len() + self.lower_bound(covered) > size {
+      return;
+    }
+
+    for column in &self.rows[row] {
+      chosen.push(*column);
+      for covered_row in &self.columns[*column] {
+        covered[*covered_row] += 1;
+      }
+      self.enumerate(covered, chosen, size, covers);
+      for covered_row in &self.columns[*column] {
+        covered[*covered_row] -= 1;
+      }
+      chosen.pop();
+    }
+  }
+
+  /**
+   * Computes a lower bound of the number of candidates still needed to cover the minterms
+   * @param covered number of chosen candidates covering each minterm
+   * @return the size of a greedily built set of uncovered minterms that share no candidate
+   */
+  fn lower_bound(&self, covered: &[usize]) -> usize {
+    let mut uncovered_rows: Vec<usize> = (0..self.rows.len())
+      .filter(|row| covered[*row] == 0)
+      .collect();
+    uncovered_rows.sort_by_key(|row| self.rows[*row].len());
+
+    let mut blocked = vec![false; self.columns.len()];
+    let mut independent_rows = 0;
+    for row in uncovered_rows {
+      if self.rows[row].iter().all(|column| !blocked[*column]) {
+        independent_rows += 1;
+        for column in &self.rows[row] {
+          blocked[*column] = true;
+        }
+      }
+    }
+
+    independent_rows
+  }
+
+  /**
+   * Checks if a cover of the given size and cost is better than the best one found so far
+   * @param size number of product terms in the cover
+   * @param cost summed cost of the product terms in the cover
+   * @return true if the cover is better; false otherwise
+   */
+  fn is_better(&self, size: usize, cost: f64) -> bool {
+    match &self.best {
+      Some((best_size, best_cost, _)) => {
+        size < *best_size || size == *best_size && cost < *best_cost
+      }
+      None => true,
+    }
+  }
+}
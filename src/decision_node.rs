@@ -0,0 +1,76 @@
+use literal_value::LiteralValue;
+use product_term::ProductTerm;
+use std::collections::HashSet;
+
+/**
+ * Enum that represents a node of a Shannon decomposition tree.
+ * Every decision selects between the cofactors of the function for the two values of its variable,
+ * like a multiplexer, and every leaf holds a cover of the remaining cofactor over the variables
+ * that were not decided on its path.
+ */
+#[derive(Clone, PartialEq, Debug)]
+pub enum DecisionNode {
+  Leaf(HashSet<ProductTerm>),
+  Decision {
+    variable: String,
+    low: Box<DecisionNode>,
+    high: Box<DecisionNode>,
+  },
+}
+
+impl DecisionNode {
+  /**
+   * Computes the depth of this tree
+   * @return the largest number of decisions on a path from this node to a leaf
+   */
+  pub fn depth(&self) -> usize {
+    match self {
+      DecisionNode::Leaf(_) => 0,
+      DecisionNode::Decision { low, high, .. } => 1 + low.depth().max(high.depth()),
+    }
+  }
+
+  /**
+   * Flattens this tree into a sum of products by prefixing the product terms of every leaf with
+   * the literals of the decisions on its path
+   * @return set containing product terms covering the function represented by this tree
+   */
+  pub fn to_sum_of_products(&self) -> HashSet<ProductTerm> {
+    let mut terms = HashSet::new();
+    self.collect_terms(&mut Vec::new(), &mut terms);
+    terms
+  }
+
+  /**
+   * Collects the product terms of the leaves below this node
+   * @param path the literals of the decisions from the root to this node
+   * @param terms set to add the prefixed product terms of the leaves to
+   */
+  fn collect_terms(
+    &self,
+    path: &mut Vec<(String, LiteralValue)>,
+    terms: &mut HashSet<ProductTerm>,
+  ) {
+    match self {
+      DecisionNode::Leaf(cover) => {
+        for term in cover {
+          let mut literals = path.clone();
+          literals.extend(term.get_literals());
+          terms.insert(ProductTerm::new_with_literals(literals));
+        }
+      }
+      DecisionNode::Decision {
+        variable,
+        low,
+        high,
+      } => {
+        path.push((String::clone(variable), LiteralValue::False));
+        low.collect_terms(path, terms);
+        path.pop();
+        path.push((String::clone(variable), LiteralValue::True));
+        high.collect_terms(path, terms);
+        path.pop();
+      }
+    }
+  }
+}
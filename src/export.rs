@@ -0,0 +1,401 @@
+use byte_encoding::write_string;
+use literal_value::LiteralValue;
+use minimization_error::MinimizationError;
+use product_term::ProductTerm;
+use std::collections::HashSet;
+use ternary_tree_minimization::TernaryTreeMinimization;
+
+/**
+ * The largest number of variables whose cube masks fit in 64 bits
+ */
+pub const MAX_MASK_VARIABLES: usize = 64;
+
+/**
+ * The URL and file name safe base64 alphabet
+ */
+pub const BASE64_ALPHABET: &[u8; 64] =
+  b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/**
+ * Exports a set cover problem as an integer linear program in the CPLEX LP format.
+ * Every prime implicant becomes a binary variable p<i> and every minterm becomes a constraint
+ * requiring at least one of the primes covering it to be selected. Solving the model with an
+ * external ILP solver yields a cover with the minimum number of product terms.
+ * @param primes the prime implicants that can be selected in the cover
+ * @param minterms the minterms that must be covered
+ * @param variable_order the variables that appear in the product terms
+ * @return the string representation of the model in the CPLEX LP format
+ * @throws error if a minterm is covered by none of the primes or there are no primes, since the model would not be well formed
+ */
+pub fn to_set_cover_ilp(
+  primes: &[ProductTerm],
+  minterms: &[u64],
+  variable_order: &[String],
+) -> Result<String, MinimizationError> {
+  let mut buffer = String::new();
+  for (index, prime) in primes.iter().enumerate() {
+    buffer.push_str(&format!(
+      "\\ p{} = {}\n",
+      index,
+      prime.to_boolean_expression()
+    ));
+  }
+
+  let variables: Vec<String> = (0..primes.len())
+    .map(|index| format!("p{}", index))
+    .collect();
+  let mut rows = Vec::new();
+  for minterm in minterms {
+    let covering: Vec<&str> = primes
+      .iter()
+      .enumerate()
+      .filter(|(_, prime)| prime.covers_minterm(*minterm, variable_order))
+      .map(|(index, _)| variables[index].as_str())
+      .collect();
+    if covering.is_empty() {
+      return Err(MinimizationError::UncoverableMinterm { minterm: *minterm });
+    }
+    rows.push(format!(" m{}: {} >= 1\n", minterm, covering.join(" + ")));
+  }
+  if variables.is_empty() {
+    return Err(MinimizationError::EmptyModel);
+  }
+
+  buffer.push_str("Minimize\n");
+  buffer.push_str(&format!(" obj: {}\n", variables.join(" + ")));
+  buffer.push_str("Subject To\n");
+  for row in rows {
+    buffer.push_str(&row);
+  }
+
+  buffer.push_str("Binary\n");
+  for variable in &variables {
+    buffer.push_str(&format!(" {}\n", variable));
+  }
+  buffer.push_str("End\n");
+
+  Ok(buffer)
+}
+
+/**
+ * Exports product terms as a Python snippet for sympy, declaring a symbol for every variable and
+ * binding their disjunction to expr, e.g. expr = (~A & B) | (A & B & C).
+ * The product terms are sorted by their cube strings so the output is deterministic.
+ * @param terms product terms to export
+ * @param variable_order the variables that appear in the product terms
+ * @return the Python source declaring the symbols and the expression
+ */
+pub fn to_sympy(terms: &HashSet<ProductTerm>, variable_order: &[String]) -> String {
+  let mut buffer = String::from("from sympy import false, symbols, true\n");
+  if variable_order.len() == 1 {
+    buffer.push_str(&format!(
+      "{} = symbols('{}')\n",
+      variable_order[0], variable_order[0]
+    ));
+  } else if !variable_order.is_empty() {
+    buffer.push_str(&format!(
+      "{} = symbols('{}')\n",
+      variable_order.join(", "),
+      variable_order.join(" ")
+    ));
+  }
+
+  let mut sorted_terms: Vec<&ProductTerm> = terms.iter().collect();
+  sorted_terms.sort_by_key(|term| term.to_cube_string(variable_order));
+  let products: Vec<String> = sorted_terms
+    .iter()
+    .map(|term| {
+      let literals: Vec<String> = variable_order
+        .iter()
+        .filter_map(|variable| match term.get_literal(variable) {
+          LiteralValue::True => Some(variable.clone()),
+          LiteralValue::False => Some(format!("~{}", variable)),
+          LiteralValue::DontCare => None,
+        })
+        .collect();
+      if literals.is_empty() {
+        "true".to_string()
+      } else {
+        format!("({})", literals.join(" & "))
+      }
+    })
+    .collect();
+  let expression = if products.is_empty() {
+    "false".to_string()
+  } else {
+    products.join(" | ")
+  };
+  buffer.push_str(&format!("expr = {}\n", expression));
+
+  buffer
+}
+
+/**
+ * Exports the adjacency of product terms as a Graphviz graph in the DOT format.
+ * Every product term becomes a node labeled with its cube string and every pair of product terms
+ * that can be merged into a single product term becomes an edge.
+ * @param terms the product terms to export
+ * @param variable_order the variables that appear in the product terms
+ * @return the string representation of the graph in the DOT format
+ */
+pub fn adjacency_dot(terms: &[ProductTerm], variable_order: &[String]) -> String {
+  let mut buffer = String::from("graph adjacency {\n");
+  for (index, term) in terms.iter().enumerate() {
+    buffer.push_str(&format!(
+      "  n{} [label=\"{}\"];\n",
+      index,
+      term.to_cube_string(variable_order)
+    ));
+  }
+  for (index, other_index) in TernaryTreeMinimization::adjacent_cubes(terms, variable_order) {
+    buffer.push_str(&format!("  n{} -- n{};\n", index, other_index));
+  }
+  buffer.push_str("}\n");
+
+  buffer
+}
+
+/**
+ * Exports product terms as a compact string that can be embedded in URLs or configuration files.
+ * The variable order and the cube masks are written as bytes: the number of variables, the name
+ * of every variable prefixed by its length, the number of product terms and the value and mask of
+ * every product term, each taking one byte per 8 variables. Counts and lengths are little endian
+ * 32-bit integers and masks are little endian. The bytes are then encoded in the URL safe base64
+ * alphabet without padding. The product terms are sorted by their cube strings so the output is
+ * deterministic.
+ * @param terms product terms to export
+ * @param variable_order the variables that appear in the product terms
+ * @return the base64 encoding of the variable order and the product terms
+ * @throws error if there are more than 64 variables
+ */
+pub fn to_base64(
+  terms: &HashSet<ProductTerm>,
+  variable_order: &[String],
+) -> Result<String, MinimizationError> {
+  if variable_order.len() > MAX_MASK_VARIABLES {
+    return Err(MinimizationError::TooManyVariables {
+      maximum: MAX_MASK_VARIABLES,
+      actual: variable_order.len(),
+    });
+  }
+
+  let mut bytes = Vec::new();
+  bytes.extend_from_slice(&(variable_order.len() as u32).to_le_bytes());
+  for variable in variable_order {
+    write_string(&mut bytes, variable);
+  }
+
+  let width = variable_order.len().div_ceil(8);
+  let mut sorted_terms: Vec<&ProductTerm> = terms.iter().collect();
+  sorted_terms.sort_by_key(|term| term.to_cube_string(variable_order));
+  bytes.extend_from_slice(&(sorted_terms.len() as u32).to_le_bytes());
+  for term in sorted_terms {
+    let (value, mask) = term.to_cube_masks(variable_order);
+    bytes.extend_from_slice(&value.to_le_bytes()[..width]);
+    bytes.extend_from_slice(&mask.to_le_bytes()[..width]);
+  }
+
+  let mut buffer = String::new();
+  for chunk in bytes.chunks(3) {
+    let group = chunk.iter().enumerate().fold(0u32, |group, (index, byte)| {
+      group | (*byte as u32) << (16 - 8 * index)
+    });
+    for index in 0..=chunk.len() {
+      buffer.push(BASE64_ALPHABET[(group >> (18 - 6 * index) & 63) as usize] as char);
+    }
+  }
+
+  Ok(buffer)
+}
+
+/**
+ * Exports the truth table of the function covered by the product terms as a hexadecimal number, as
+ * used for lookup tables: bit k of the number, the least significant bit being bit 0, is the value
+ * of minterm k. The digits are lowercase, the most significant first, one per 4 minterms (at
+ * least one digit), without a 0x prefix.
+ * @param terms product terms covering the function
+ * @param variable_order the variables that appear in the product terms
+ * @return the hexadecimal truth table of the function
+ * @throws error if there are too many variables to enumerate the minterms
+ */
+pub fn to_hex_truth_table(
+  terms: &HashSet<ProductTerm>,
+  variable_order: &[String],
+) -> Result<String, MinimizationError> {
+  let table = TernaryTreeMinimization::truth_table(terms, variable_order)?;
+
+  Ok(
+    table
+      .chunks(4)
+      .rev()
+      .map(|chunk| {
+        let digit = chunk
+          .iter()
+          .enumerate()
+          .fold(0, |digit, (bit, value)| digit | (*value as u32) << bit);
+        std::char::from_digit(digit, 16).unwrap()
+      })
+      .collect(),
+  )
+}
+
+/**
+ * Module for tests regarding the export functions
+ */
+#[cfg(test)]
+mod export_tests {
+  use super::*;
+
+  #[test]
+  fn test_to_set_cover_ilp_01() {
+    let primes = vec![
+      ProductTerm::new_with_literals(vec![
+        ("A".to_string(), LiteralValue::False),
+        ("B".to_string(), LiteralValue::DontCare),
+      ]),
+      ProductTerm::new_with_literals(vec![
+        ("A".to_string(), LiteralValue::DontCare),
+        ("B".to_string(), LiteralValue::False),
+      ]),
+    ];
+    let minterms = vec![0, 1, 2];
+
+    let model = to_set_cover_ilp(&primes, &minterms, &["A".to_string(), "B".to_string()]).unwrap();
+    let constraints: Vec<&str> = model
+      .lines()
+      .skip_while(|line| *line != "Subject To")
+      .skip(1)
+      .take_while(|line| *line != "Binary")
+      .collect();
+    let binaries: Vec<&str> = model
+      .lines()
+      .skip_while(|line| *line != "Binary")
+      .skip(1)
+      .take_while(|line| *line != "End")
+      .collect();
+
+    assert_eq!(
+      constraints,
+      vec![" m0: p0 + p1 >= 1", " m1: p0 >= 1", " m2: p1 >= 1"]
+    );
+    assert_eq!(binaries.len(), primes.len());
+  }
+
+  #[test]
+  fn test_to_set_cover_ilp_02_fail() {
+    let variable_order = vec!["A".to_string(), "B".to_string()];
+    let primes = vec![ProductTerm::from_cube_string("0-", &variable_order).unwrap()];
+
+    assert_eq!(
+      to_set_cover_ilp(&primes, &[0, 2], &variable_order),
+      Err(MinimizationError::UncoverableMinterm { minterm: 2 })
+    );
+    assert_eq!(
+      to_set_cover_ilp(&[], &[1], &variable_order),
+      Err(MinimizationError::UncoverableMinterm { minterm: 1 })
+    );
+    assert_eq!(
+      to_set_cover_ilp(&[], &[], &variable_order),
+      Err(MinimizationError::EmptyModel)
+    );
+  }
+
+  #[test]
+  fn test_to_sympy_01() {
+    let variable_order = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+    let mut terms = HashSet::new();
+    terms.insert(ProductTerm::from_cube_string("01-", &variable_order).unwrap());
+    terms.insert(ProductTerm::from_cube_string("111", &variable_order).unwrap());
+
+    let source = to_sympy(&terms, &variable_order);
+    let lines: Vec<&str> = source.lines().collect();
+
+    assert_eq!(
+      lines,
+      vec![
+        "from sympy import false, symbols, true",
+        "A, B, C = symbols('A B C')",
+        "expr = (~A & B) | (A & B & C)",
+      ]
+    );
+    assert_eq!(
+      to_sympy(&HashSet::new(), &variable_order).lines().last(),
+      Some("expr = false")
+    );
+  }
+
+  #[test]
+  fn test_adjacency_dot_01() {
+    let variable_order = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+    let terms: Vec<ProductTerm> = ["000", "001", "011", "111", "1-0", "0-0"]
+      .iter()
+      .map(|cube| ProductTerm::from_cube_string(cube, &variable_order).unwrap())
+      .collect();
+
+    let graph = adjacency_dot(&terms, &variable_order);
+    let edges = graph.lines().filter(|line| line.contains(" -- ")).count();
+
+    assert!(graph.starts_with("graph adjacency {\n"));
+    assert!(graph.contains("  n4 [label=\"1-0\"];\n"));
+    assert_eq!(
+      edges,
+      TernaryTreeMinimization::adjacent_cubes(&terms, &variable_order).len()
+    );
+    assert_eq!(edges, 4);
+  }
+
+  #[test]
+  fn test_to_base64_01() {
+    let variable_order = vec!["A".to_string()];
+    let mut terms = HashSet::new();
+    terms.insert(ProductTerm::from_cube_string("1", &variable_order).unwrap());
+
+    // 01 00 00 00 | 01 00 00 00 'A' | 01 00 00 00 | 01 00
+    assert_eq!(
+      to_base64(&terms, &variable_order).unwrap(),
+      "AQAAAAEAAABBAQAAAAEA"
+    );
+  }
+
+  #[test]
+  fn test_to_base64_02_fail() {
+    let variable_order: Vec<String> = (0..65).map(|index| format!("x{}", index)).collect();
+
+    assert_eq!(
+      to_base64(&HashSet::new(), &variable_order),
+      Err(MinimizationError::TooManyVariables {
+        maximum: 64,
+        actual: 65
+      })
+    );
+  }
+
+  #[test]
+  fn test_to_hex_truth_table_01() {
+    let variable_order: Vec<String> = ["A", "B", "C", "D", "E"]
+      .iter()
+      .map(|variable| variable.to_string())
+      .collect();
+    let mut terms = HashSet::new();
+    for cube in &["1-0-1", "0--11", "00000"] {
+      terms.insert(ProductTerm::from_cube_string(cube, &variable_order).unwrap());
+    }
+
+    let hex = to_hex_truth_table(&terms, &variable_order).unwrap();
+    let decoded =
+      TernaryTreeMinimization::from_hex_truth_table(&hex, variable_order.len(), &variable_order)
+        .unwrap();
+
+    assert_eq!(hex.len(), 8);
+    assert_eq!(
+      TernaryTreeMinimization::minterms(&decoded, &variable_order),
+      TernaryTreeMinimization::minterms(&terms, &variable_order)
+    );
+    let mut conjunction = HashSet::new();
+    conjunction.insert(ProductTerm::from_cube_string("11", &variable_order[..2]).unwrap());
+    assert_eq!(
+      to_hex_truth_table(&conjunction, &variable_order[..2]).unwrap(),
+      "8"
+    );
+  }
+}
@@ -0,0 +1,27 @@
+/**
+ * Enum that represents the class of a boolean function.
+ * A function is reported in the first class it belongs to, in the order of the variants.
+ */
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FunctionClass {
+  /**
+   * The function is always true or always false
+   */
+  Constant,
+  /**
+   * The function is the exclusive or of a subset of the variables, possibly negated
+   */
+  Linear,
+  /**
+   * The function is true exactly when a weighted sum of its variables reaches a threshold
+   */
+  Threshold,
+  /**
+   * Every variable of the function appears with a single polarity
+   */
+  Unate,
+  /**
+   * Some variable of the function appears with both polarities
+   */
+  Binate,
+}
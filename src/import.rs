@@ -0,0 +1,132 @@
+use byte_encoding::{read_bytes, read_string, read_u32};
+use export::{BASE64_ALPHABET, MAX_MASK_VARIABLES};
+use minimization_error::MinimizationError;
+use product_term::ProductTerm;
+use std::collections::HashSet;
+
+/**
+ * Imports product terms exported by export::to_base64
+ * @param s the base64 encoding of the variable order and the product terms
+ * @return a tuple of the form (product terms, variable order)
+ * @throws error if a character is not in the URL safe base64 alphabet, the decoded bytes are truncated, contain invalid values or have trailing data, or there are more than 64 variables
+ */
+pub fn from_base64(s: &str) -> Result<(HashSet<ProductTerm>, Vec<String>), MinimizationError> {
+  let mut bytes = Vec::new();
+  let mut group = 0u32;
+  let mut bits = 0;
+  for character in s.chars() {
+    let digit = BASE64_ALPHABET
+      .iter()
+      .position(|symbol| *symbol as char == character)
+      .ok_or(MinimizationError::InvalidCharacter(character))?;
+    group = group << 6 | digit as u32;
+    bits += 6;
+    if bits >= 8 {
+      bits -= 8;
+      bytes.push((group >> bits) as u8);
+      group &= (1 << bits) - 1;
+    }
+  }
+  if bits >= 6 || group != 0 {
+    return Err(MinimizationError::InvalidEncoding {
+      position: bytes.len(),
+    });
+  }
+
+  let mut position = 0;
+  let number_of_vars = read_u32(&bytes, &mut position)? as usize;
+  if number_of_vars > MAX_MASK_VARIABLES {
+    return Err(MinimizationError::TooManyVariables {
+      maximum: MAX_MASK_VARIABLES,
+      actual: number_of_vars,
+    });
+  }
+  let mut variable_order = Vec::new();
+  for _ in 0..number_of_vars {
+    variable_order.push(read_string(&bytes, &mut position)?);
+  }
+
+  let width = number_of_vars.div_ceil(8);
+  let unused_bits = if number_of_vars == MAX_MASK_VARIABLES {
+    0
+  } else {
+    !0u64 << number_of_vars
+  };
+  let number_of_terms = read_u32(&bytes, &mut position)?;
+  let mut terms = HashSet::new();
+  for _ in 0..number_of_terms {
+    let start = position;
+    let value = read_mask(&bytes, &mut position, width)?;
+    let mask = read_mask(&bytes, &mut position, width)?;
+    if (value | mask) & unused_bits != 0 || value & mask != 0 {
+      return Err(MinimizationError::InvalidEncoding { position: start });
+    }
+    terms.insert(ProductTerm::from_cube_masks(value, mask, &variable_order));
+  }
+
+  if position != bytes.len() {
+    return Err(MinimizationError::InvalidEncoding { position });
+  }
+
+  Ok((terms, variable_order))
+}
+
+/**
+ * Reads a little endian cube mask of the given width, advancing the position past it
+ * @param bytes the bytes to read from
+ * @param position the position of the mask
+ * @param width the number of bytes of the mask
+ * @return the mask that was read
+ * @throws error if there are not enough bytes left
+ */
+fn read_mask(bytes: &[u8], position: &mut usize, width: usize) -> Result<u64, MinimizationError> {
+  let read = read_bytes(bytes, position, width)?;
+  Ok(
+    read
+      .iter()
+      .rev()
+      .fold(0, |mask, byte| mask << 8 | *byte as u64),
+  )
+}
+
+/**
+ * Module for tests regarding the import functions
+ */
+#[cfg(test)]
+mod import_tests {
+  use super::*;
+  use export::to_base64;
+
+  #[test]
+  fn test_from_base64_01() {
+    let variable_order: Vec<String> = (0..10).map(|index| format!("x{}", index)).collect();
+    let mut terms = HashSet::new();
+    for cube in &["1-0-----01", "0000000000", "----------", "11-1-1-1-1"] {
+      terms.insert(ProductTerm::from_cube_string(cube, &variable_order).unwrap());
+    }
+
+    let (decoded_terms, decoded_order) =
+      from_base64(&to_base64(&terms, &variable_order).unwrap()).unwrap();
+
+    assert_eq!(decoded_order, variable_order);
+    assert_eq!(decoded_terms, terms);
+    assert_eq!(
+      from_base64(&to_base64(&HashSet::new(), &[]).unwrap()).unwrap(),
+      (HashSet::new(), Vec::new())
+    );
+  }
+
+  #[test]
+  fn test_from_base64_02_fail() {
+    let variable_order = vec!["A".to_string(), "B".to_string()];
+    let mut terms = HashSet::new();
+    terms.insert(ProductTerm::from_cube_string("1-", &variable_order).unwrap());
+    let encoded = to_base64(&terms, &variable_order).unwrap();
+
+    assert_eq!(
+      from_base64("AQAA=AAA").unwrap_err(),
+      MinimizationError::InvalidCharacter('=')
+    );
+    assert!(from_base64(&encoded[..encoded.len() - 2]).is_err());
+  }
+}
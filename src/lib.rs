@@ -1 +1,20 @@
+extern crate linked_hash_map;
 
+mod byte_encoding;
+mod cover_selection;
+pub mod decision_node;
+pub mod export;
+pub mod function_class;
+pub mod import;
+mod linear_program;
+pub mod literal_value;
+pub mod minimization_error;
+pub mod minimization_options;
+pub mod order_stability;
+pub mod pla;
+pub mod prime_implicant_iter;
+pub mod product_term;
+pub mod reduced_manager;
+pub mod reduced_node;
+pub mod ternary_node;
+pub mod ternary_tree_minimization;
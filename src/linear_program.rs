@@ -0,0 +1,163 @@
+/**
+ * Tolerance used when comparing the floating point values of the tableau
+ */
+const EPSILON: f64 = 1e-9;
+
+/**
+ * Struct that represents a linear program over nonnegative variables whose constraints are all of
+ * the form a * x >= b. The program is solved with the two phase simplex method on a dense tableau,
+ * using Bland's rule so that the search always terminates.
+ */
+pub struct LinearProgram {
+  columns: usize,
+  rows: Vec<Vec<f64>>,
+  bounds: Vec<f64>,
+}
+
+impl LinearProgram {
+  /**
+   * Creates a new linear program without constraints
+   * @param columns the number of variables of the program
+   * @return a new linear program over the given number of variables
+   */
+  pub fn new(columns: usize) -> LinearProgram {
+    LinearProgram {
+      columns,
+      rows: Vec::new(),
+      bounds: Vec::new(),
+    }
+  }
+
+  /**
+   * Adds the constraint coefficients * x >= bound
+   * @param coefficients the coefficient of each variable
+   * @param bound the lower bound of the weighted sum of the variables
+   */
+  pub fn add_constraint(&mut self, coefficients: Vec<f64>, bound: f64) {
+    self.rows.push(coefficients);
+    self.bounds.push(bound);
+  }
+
+  /**
+   * Searches for the nonnegative variables that satisfy every constraint with the lowest cost
+   * @param costs the cost of each variable; the costs must keep the program bounded
+   * @return the value of each variable in an optimal solution; None if the program is infeasible
+   */
+  pub fn minimize(&self, costs: &[f64]) -> Option<Vec<f64>> {
+    let number_of_rows = self.rows.len();
+    let artificial_start = self.columns + number_of_rows;
+    let width = artificial_start + number_of_rows + 1;
+
+    let mut tableau = vec![vec![0.0; width]; number_of_rows];
+    for (row, coefficients) in self.rows.iter().enumerate() {
+      let sign = if self.bounds[row] < 0.0 { -1.0 } else { 1.0 };
+      for (column, coefficient) in coefficients.iter().enumerate() {
+        tableau[row][column] = sign * coefficient;
+      }
+      tableau[row][self.columns + row] = -sign;
+      tableau[row][artificial_start + row] = 1.0;
+      tableau[row][width - 1] = sign * self.bounds[row];
+    }
+    let mut basis: Vec<usize> = (artificial_start..artificial_start + number_of_rows).collect();
+
+    let mut phase_one_costs = vec![0.0; width - 1];
+    for cost in &mut phase_one_costs[artificial_start..] {
+      *cost = 1.0;
+    }
+    LinearProgram::optimize(&mut tableau, &mut basis, &phase_one_costs, width - 1);
+    let infeasibility: f64 = (0..number_of_rows)
+      .filter(|row| basis[*row] >= artificial_start)
+      .map(|row| tableau[row][width - 1])
+      .sum();
+    if infeasibility > EPSILON {
+      return None;
+    }
+
+    for row in 0..number_of_rows {
+      if basis[row] >= artificial_start {
+        if let Some(column) =
+          (0..artificial_start).find(|column| tableau[row][*column].abs() > EPSILON)
+        {
+          LinearProgram::pivot(&mut tableau, &mut basis, row, column);
+        }
+      }
+    }
+
+    let mut phase_two_costs = vec![0.0; width - 1];
+    phase_two_costs[..self.columns].copy_from_slice(costs);
+    LinearProgram::optimize(&mut tableau, &mut basis, &phase_two_costs, artificial_start);
+
+    let mut solution = vec![0.0; self.columns];
+    for (row, column) in basis.iter().enumerate() {
+      if *column < self.columns {
+        solution[*column] = tableau[row][width - 1];
+      }
+    }
+
+    Some(solution)
+  }
+
+  /**
+   * Pivots the tableau until no allowed column can lower the cost
+   * @param tableau the rows of the tableau, ending with their right hand side
+   * @param basis the basic column of each row
+   * @param costs the cost of each column
+   * @param allowed the number of leading columns that may enter the basis
+   */
+  fn optimize(tableau: &mut [Vec<f64>], basis: &mut [usize], costs: &[f64], allowed: usize) {
+    let last = costs.len();
+    loop {
+      let entering = (0..allowed).find(|column| {
+        let reduced_cost = costs[*column]
+          - (0..tableau.len())
+            .map(|row| costs[basis[row]] * tableau[row][*column])
+            .sum::<f64>();
+        reduced_cost < -EPSILON
+      });
+      let column = match entering {
+        Some(column) => column,
+        None => return,
+      };
+
+      let leaving = (0..tableau.len())
+        .filter(|row| tableau[*row][column] > EPSILON)
+        .min_by(|first, second| {
+          let first_ratio = tableau[*first][last] / tableau[*first][column];
+          let second_ratio = tableau[*second][last] / tableau[*second][column];
+          first_ratio
+            .partial_cmp(&second_ratio)
+            .unwrap()
+            .then(basis[*first].cmp(&basis[*second]))
+        });
+      match leaving {
+        Some(row) => LinearProgram::pivot(tableau, basis, row, column),
+        None => return,
+      }
+    }
+  }
+
+  /**
+   * Makes the given column basic in the given row
+   * @param tableau the rows of the tableau, ending with their right hand side
+   * @param basis the basic column of each row
+   * @param row the row whose basic column leaves the basis
+   * @param column the column entering the basis
+   */
+  fn pivot(tableau: &mut [Vec<f64>], basis: &mut [usize], row: usize, column: usize) {
+    let pivot_value = tableau[row][column];
+    for value in &mut tableau[row] {
+      *value /= pivot_value;
+    }
+    let pivot_row = tableau[row].clone();
+    for (other_row, values) in tableau.iter_mut().enumerate() {
+      let factor = values[column];
+      if other_row == row || factor == 0.0 {
+        continue;
+      }
+      for (value, pivot_row_value) in values.iter_mut().zip(&pivot_row) {
+        *value -= factor * pivot_row_value;
+      }
+    }
+    basis[row] = column;
+  }
+}
@@ -8,6 +8,20 @@ pub enum LiteralValue {
   DontCare = 2,
 }
 
+impl LiteralValue {
+  /**
+   * Negates this literal value
+   * @return the opposite value for a true or false literal; don't care otherwise
+   */
+  pub fn negate(self) -> LiteralValue {
+    match self {
+      LiteralValue::True => LiteralValue::False,
+      LiteralValue::False => LiteralValue::True,
+      LiteralValue::DontCare => LiteralValue::DontCare,
+    }
+  }
+}
+
 impl Hash for LiteralValue {
   /**
    * Hash function for the literal value
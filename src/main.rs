@@ -1,10 +1,9 @@
-extern crate linked_hash_map;
+extern crate tt_min;
 
-mod literal_value;
-mod product_term;
-mod ternary_node;
-mod ternary_tree_minimization;
 use std::collections::HashSet;
+use tt_min::literal_value;
+use tt_min::product_term;
+use tt_min::ternary_tree_minimization;
 
 fn main() {
   let mut term = product_term::ProductTerm::new();
@@ -18,10 +17,9 @@ fn main() {
   let mut set = HashSet::new();
   set.insert(term);
   set.insert(other);
-  let mut vec = vec!["A".to_string(), "B".to_string()];
+  let vec = vec!["A".to_string(), "B".to_string()];
 
-  if let Ok(result_set) = ternary_tree_minimization::TernaryTreeMinimization::apply(&set, &mut vec)
-  {
+  if let Ok(result_set) = ternary_tree_minimization::TernaryTreeMinimization::apply(&set, &vec) {
     for term in result_set {
       println!("{}", term.to_boolean_expression());
     }
@@ -0,0 +1,68 @@
+/**
+ * Enum that represents the errors that can occur while building or minimizing product terms
+ */
+#[derive(Debug, PartialEq)]
+pub enum MinimizationError {
+  InvalidLength { expected: usize, actual: usize },
+  InvalidCharacter(char),
+  TooManyVariables { maximum: usize, actual: usize },
+  InvalidDirective(String),
+  InvalidEncoding { position: usize },
+  ContradictoryExample { minterm: u64 },
+  ForbiddenMinterm { minterm: u64 },
+  UncoverableMinterm { minterm: u64 },
+  EmptyModel,
+  FrozenOffSetMinterm { minterm: u64 },
+  ForbiddenFrozenCube { cube: String },
+}
+
+impl std::fmt::Display for MinimizationError {
+  /**
+   * Method that prints this error's description
+   */
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      MinimizationError::InvalidLength { expected, actual } => {
+        write!(f, "Expected {} positions, found {}!", expected, actual)
+      }
+      MinimizationError::InvalidCharacter(character) => {
+        write!(f, "Invalid character '{}'!", character)
+      }
+      MinimizationError::TooManyVariables { maximum, actual } => write!(
+        f,
+        "Too many variables to enumerate: {} (at most {})!",
+        actual, maximum
+      ),
+      MinimizationError::InvalidDirective(directive) => {
+        write!(f, "Invalid PLA directive '{}'!", directive)
+      }
+      MinimizationError::InvalidEncoding { position } => {
+        write!(f, "Invalid encoding at byte {}!", position)
+      }
+      MinimizationError::ContradictoryExample { minterm } => {
+        write!(f, "Minterm {} is labeled both true and false!", minterm)
+      }
+      MinimizationError::ForbiddenMinterm { minterm } => write!(
+        f,
+        "Minterm {} can only be covered by forbidden cubes!",
+        minterm
+      ),
+      MinimizationError::UncoverableMinterm { minterm } => {
+        write!(f, "Minterm {} is covered by none of the primes!", minterm)
+      }
+      MinimizationError::EmptyModel => write!(f, "The model has no variables!"),
+      MinimizationError::FrozenOffSetMinterm { minterm } => write!(
+        f,
+        "A frozen cube covers minterm {}, outside the on and don't care sets!",
+        minterm
+      ),
+      MinimizationError::ForbiddenFrozenCube { cube } => write!(
+        f,
+        "Frozen cube {} is equal to or covers a forbidden cube!",
+        cube
+      ),
+    }
+  }
+}
+
+impl std::error::Error for MinimizationError {}
@@ -0,0 +1,80 @@
+use product_term::ProductTerm;
+use std::collections::HashSet;
+
+/**
+ * Struct that contains the options of a minimization.
+ * The maximum number of passes bounds the heuristic minimization, which rejects every other
+ * option. Of the other options, used by the exact minimization, all but the frozen and forbidden cubes only break ties between covers
+ * with the same number of product terms and literals.
+ */
+pub struct MinimizationOptions {
+  /**
+   * Known good product terms that are preferred over other ones. A product term matches a library
+   * product term if both have the same specified literals in the variable order, wherever their
+   * don't care literals are placed.
+   */
+  pub cube_library: HashSet<ProductTerm>,
+  /**
+   * Product terms copied verbatim to the result, without being expanded or merged. Only the
+   * minterms they leave uncovered are minimized. They must not cover any minterm outside the on
+   * and don't care sets, since that would change the function.
+   */
+  pub frozen_cubes: HashSet<ProductTerm>,
+  /**
+   * Product terms that must not appear in the result. No product term equal to or covering a
+   * forbidden product term is used; smaller product terms are used instead. Product terms covered
+   * by a forbidden product term are still allowed, since forbidding them as well would leave no
+   * smaller product terms to fall back on. A frozen cube equal to or covering a forbidden product
+   * term is an error.
+   */
+  pub forbidden_cubes: HashSet<ProductTerm>,
+  /**
+   * Whether covers with fewer false literals (fewer inverted inputs) are preferred. This tie is
+   * broken after the cube library one.
+   */
+  pub prefer_positive: bool,
+  /**
+   * Maximum number of build and merge passes of the heuristic minimization. When 0, one pass more
+   * than the number of variables is made, so that every variable is rotated once.
+   */
+  pub max_passes: usize,
+}
+
+impl MinimizationOptions {
+  /**
+   * Creates new default options
+   * @return options with an empty cube library, no frozen or forbidden cubes, no polarity preference and the default number of passes
+   */
+  pub fn new() -> MinimizationOptions {
+    MinimizationOptions {
+      cube_library: HashSet::new(),
+      frozen_cubes: HashSet::new(),
+      forbidden_cubes: HashSet::new(),
+      prefer_positive: false,
+      max_passes: 0,
+    }
+  }
+
+  /**
+   * Checks if a product term matches any of the library product terms
+   * @param term product term to check
+   * @param variable_order the variables that appear in the product terms
+   * @return true if the product term matches a library product term; false otherwise
+   */
+  pub fn matches_library(&self, term: &ProductTerm, variable_order: &[String]) -> bool {
+    let pattern = term.to_cube_string(variable_order).replace('-', "");
+    self
+      .cube_library
+      .iter()
+      .any(|library_term| library_term.to_cube_string(variable_order).replace('-', "") == pattern)
+  }
+}
+
+impl Default for MinimizationOptions {
+  /**
+   * Creates new default options
+   */
+  fn default() -> MinimizationOptions {
+    MinimizationOptions::new()
+  }
+}
@@ -0,0 +1,16 @@
+/**
+ * Struct that describes how the result of the algorithm changes between two variable orders.
+ * The covers should always be equivalent, but the rotation heuristic often makes them differ in
+ * their product terms.
+ */
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OrderStability {
+  /**
+   * Whether both covers represent the same function
+   */
+  pub equivalent: bool,
+  /**
+   * Whether both covers contain the same product terms
+   */
+  pub identical: bool,
+}
@@ -0,0 +1,258 @@
+use minimization_error::MinimizationError;
+use product_term::ProductTerm;
+use std::collections::HashSet;
+use ternary_tree_minimization::TernaryTreeMinimization;
+
+/**
+ * Struct that represents a multiple output function read from a PLA file.
+ * Each output is described by the product terms of its on set and of its don't care set.
+ */
+#[derive(Debug)]
+pub struct Pla {
+  /**
+   * The input variables, in the order of the input columns
+   */
+  pub variable_order: Vec<String>,
+  /**
+   * The output names, in the order of the output columns
+   */
+  pub outputs: Vec<String>,
+  /**
+   * The on set of each output
+   */
+  pub on_sets: Vec<HashSet<ProductTerm>>,
+  /**
+   * The don't care set of each output
+   */
+  pub dc_sets: Vec<HashSet<ProductTerm>>,
+}
+
+/**
+ * Parses a PLA file in the espresso format.
+ * The .i, .o, .ilb, .ob, .p, .type, .phase and .e directives are supported. Each row contains an
+ * input cube followed by one character per output: 1 adds the cube to the on set, - or 2 to the
+ * don't care set, 0 to the off set (only meaningful for the fr and fdr types) and ~ leaves the
+ * output unspecified. For the fr and fdr types, the minterms in neither the on nor the off set are
+ * don't care. An output whose .phase character is 0 is complemented: its on set becomes its off set.
+ * Variables and outputs without a .ilb or .ob name are named x0, x1, ... and f0, f1, ...
+ * @param source the contents of the PLA file
+ * @return the function described by the PLA file
+ * @throws error if a directive is unknown or malformed or a row does not match the declared sizes
+ */
+pub fn parse_pla_full(source: &str) -> Result<Pla, MinimizationError> {
+  let mut inputs = None;
+  let mut output_count = None;
+  let mut variable_order = None;
+  let mut outputs = None;
+  let mut pla_type = "fd".to_string();
+  let mut phase = None;
+  let mut rows = Vec::new();
+
+  for line in source.lines() {
+    let line = line.split('#').next().unwrap_or("").trim();
+    if line.is_empty() {
+      continue;
+    }
+    if !line.starts_with('.') {
+      rows.push(line.split_whitespace().collect::<String>());
+      continue;
+    }
+
+    let mut words = line.split_whitespace();
+    let directive = words.next().unwrap_or("");
+    let arguments: Vec<String> = words.map(|word| word.to_string()).collect();
+    let invalid = || MinimizationError::InvalidDirective(line.to_string());
+    match directive {
+      ".i" | ".o" => {
+        let count: usize = match arguments.first().map(|argument| argument.parse()) {
+          Some(Ok(count)) if arguments.len() == 1 => count,
+          _ => return Err(invalid()),
+        };
+        if directive == ".i" {
+          inputs = Some(count);
+        } else {
+          output_count = Some(count);
+        }
+      }
+      ".ilb" => variable_order = Some(arguments),
+      ".ob" => outputs = Some(arguments),
+      ".type" => match arguments.first().map(|argument| argument.as_str()) {
+        Some("f") | Some("fd") | Some("fr") | Some("fdr") if arguments.len() == 1 => {
+          pla_type = arguments[0].clone()
+        }
+        _ => return Err(invalid()),
+      },
+      ".phase" => match arguments.first() {
+        Some(phases) if arguments.len() == 1 => phase = Some(phases.clone()),
+        _ => return Err(invalid()),
+      },
+      ".p" => {}
+      ".e" | ".end" => break,
+      _ => return Err(invalid()),
+    }
+  }
+
+  let inputs = inputs.ok_or_else(|| MinimizationError::InvalidDirective(".i".to_string()))?;
+  let output_count =
+    output_count.ok_or_else(|| MinimizationError::InvalidDirective(".o".to_string()))?;
+  let variable_order =
+    variable_order.unwrap_or_else(|| (0..inputs).map(|index| format!("x{}", index)).collect());
+  let outputs = outputs.unwrap_or_else(|| {
+    (0..output_count)
+      .map(|index| format!("f{}", index))
+      .collect()
+  });
+  if variable_order.len() != inputs {
+    return Err(MinimizationError::InvalidLength {
+      expected: inputs,
+      actual: variable_order.len(),
+    });
+  }
+  if outputs.len() != output_count {
+    return Err(MinimizationError::InvalidLength {
+      expected: output_count,
+      actual: outputs.len(),
+    });
+  }
+
+  let mut on_sets = vec![HashSet::new(); output_count];
+  let mut dc_sets = vec![HashSet::new(); output_count];
+  let mut off_sets = vec![HashSet::new(); output_count];
+  for row in rows {
+    let characters: Vec<char> = row.chars().collect();
+    if characters.len() != inputs + output_count {
+      return Err(MinimizationError::InvalidLength {
+        expected: inputs + output_count,
+        actual: characters.len(),
+      });
+    }
+    let cube: String = characters[..inputs].iter().collect();
+    let term = ProductTerm::from_cube_string(&cube, &variable_order)?;
+    for (output, character) in characters[inputs..].iter().enumerate() {
+      match character {
+        '1' => {
+          on_sets[output].insert(term.clone());
+        }
+        '-' | '2' => {
+          dc_sets[output].insert(term.clone());
+        }
+        '0' => {
+          off_sets[output].insert(term.clone());
+        }
+        '~' => {}
+        _ => return Err(MinimizationError::InvalidCharacter(*character)),
+      }
+    }
+  }
+
+  if pla_type.contains('r') {
+    for output in 0..output_count {
+      let mut specified: HashSet<ProductTerm> = on_sets[output].clone();
+      specified.extend(off_sets[output].iter().cloned());
+      dc_sets[output].extend(TernaryTreeMinimization::complement_via_tree(
+        &specified,
+        &variable_order,
+      ));
+    }
+  }
+
+  if let Some(phase) = phase {
+    let phases: Vec<char> = phase.chars().collect();
+    if phases.len() != output_count {
+      return Err(MinimizationError::InvalidLength {
+        expected: output_count,
+        actual: phases.len(),
+      });
+    }
+    for (output, output_phase) in phases.iter().enumerate() {
+      match output_phase {
+        '1' => {}
+        '0' => {
+          let mut care: HashSet<ProductTerm> = on_sets[output].clone();
+          care.extend(dc_sets[output].iter().cloned());
+          on_sets[output] = TernaryTreeMinimization::complement_via_tree(&care, &variable_order);
+        }
+        _ => return Err(MinimizationError::InvalidCharacter(*output_phase)),
+      }
+    }
+  }
+
+  Ok(Pla {
+    variable_order,
+    outputs,
+    on_sets,
+    dc_sets,
+  })
+}
+
+/**
+ * Module for tests regarding the PLA parser
+ */
+#[cfg(test)]
+mod pla_tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_pla_full_01() {
+    let source = "\
+# half adder whose odd output does not matter when both inputs are set
+.i 2
+.o 2
+.ilb a b
+.ob carry odd
+.p 3
+11 1-
+10 ~1
+01 01
+.e
+";
+
+    let pla = parse_pla_full(source).unwrap();
+    let carry_dc = TernaryTreeMinimization::minterms(&pla.dc_sets[0], &pla.variable_order);
+    let odd_dc = TernaryTreeMinimization::minterms(&pla.dc_sets[1], &pla.variable_order);
+
+    assert_eq!(pla.variable_order, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(pla.outputs, vec!["carry".to_string(), "odd".to_string()]);
+    assert_eq!(
+      TernaryTreeMinimization::minterms(&pla.on_sets[0], &pla.variable_order),
+      [3].iter().cloned().collect()
+    );
+    assert_eq!(
+      TernaryTreeMinimization::minterms(&pla.on_sets[1], &pla.variable_order),
+      [1, 2].iter().cloned().collect()
+    );
+    assert!(carry_dc.is_empty());
+    assert_eq!(odd_dc, [3].iter().cloned().collect());
+  }
+
+  #[test]
+  fn test_parse_pla_full_02() {
+    let source = ".i 2\n.o 1\n.phase 0\n1- 1\n01 -\n";
+
+    let pla = parse_pla_full(source).unwrap();
+
+    assert_eq!(
+      TernaryTreeMinimization::minterms(&pla.on_sets[0], &pla.variable_order),
+      [0].iter().cloned().collect()
+    );
+    assert_eq!(
+      TernaryTreeMinimization::minterms(&pla.dc_sets[0], &pla.variable_order),
+      [1].iter().cloned().collect()
+    );
+  }
+
+  #[test]
+  fn test_parse_pla_full_03_fail() {
+    assert_eq!(
+      parse_pla_full(".i 2\n.o 1\n.mv 3\n").unwrap_err(),
+      MinimizationError::InvalidDirective(".mv 3".to_string())
+    );
+    assert_eq!(
+      parse_pla_full(".i 2\n.o 1\n1-0 1\n").unwrap_err(),
+      MinimizationError::InvalidLength {
+        expected: 3,
+        actual: 4
+      }
+    );
+  }
+}
@@ -0,0 +1,115 @@
+use product_term::ProductTerm;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+/**
+ * Cubes that took part in a merge step and cubes produced by it
+ */
+type MergeStep = (HashSet<(u64, u64)>, BTreeSet<(u64, u64)>);
+
+/**
+ * Iterator over the prime implicants of a function, generated with the Quine-McCluskey tabulation.
+ * Each step merges the cubes of the current level into the cubes of the next level; the cubes that
+ * could not be merged are maximal and are yielded before the next level is computed, so the caller
+ * can report progress or stop the generation early.
+ */
+pub struct PrimeImplicantIter {
+  variable_order: Vec<String>,
+  on_minterms: BTreeSet<u64>,
+  cubes: BTreeSet<(u64, u64)>,
+  confirmed_primes: VecDeque<ProductTerm>,
+}
+
+impl PrimeImplicantIter {
+  /**
+   * Creates a new prime implicant iterator
+   * @param on_minterms the minterms for which the function is true
+   * @param dc_minterms the minterms for which the function value does not matter
+   * @param variable_order the variables that appear in the product terms
+   * @return a new iterator starting from the level of the minterms
+   */
+  pub fn new(
+    on_minterms: BTreeSet<u64>,
+    dc_minterms: BTreeSet<u64>,
+    variable_order: &[String],
+  ) -> PrimeImplicantIter {
+    let cubes = on_minterms
+      .iter()
+      .chain(dc_minterms.iter())
+      .map(|minterm| (*minterm, 0))
+      .collect();
+
+    PrimeImplicantIter {
+      variable_order: variable_order.to_vec(),
+      on_minterms,
+      cubes,
+      confirmed_primes: VecDeque::new(),
+    }
+  }
+
+  /**
+   * Merges the cubes of the current level, confirming the cubes that cannot be merged as primes
+   */
+  fn next_level(&mut self) {
+    let (merged, next_cubes) =
+      merge_adjacent_cubes(&self.cubes, &self.cubes, self.variable_order.len());
+
+    for (value, mask) in &self.cubes {
+      if !merged.contains(&(*value, *mask)) {
+        let prime = ProductTerm::from_cube_masks(*value, *mask, &self.variable_order);
+        if self
+          .on_minterms
+          .iter()
+          .any(|minterm| prime.covers_minterm(*minterm, &self.variable_order))
+        {
+          self.confirmed_primes.push_back(prime);
+        }
+      }
+    }
+    self.cubes = next_cubes;
+  }
+}
+
+impl Iterator for PrimeImplicantIter {
+  type Item = ProductTerm;
+
+  /**
+   * Yields the next confirmed prime implicant, computing new levels as needed
+   * @return the next prime implicant; None once every level was processed
+   */
+  fn next(&mut self) -> Option<ProductTerm> {
+    while self.confirmed_primes.is_empty() && !self.cubes.is_empty() {
+      self.next_level();
+    }
+
+    self.confirmed_primes.pop_front()
+  }
+}
+
+/**
+ * Merges the cubes with the cubes that differ from them only by one more true literal, as in one
+ * step of the Quine-McCluskey tabulation
+ * @param cubes the cubes to merge, as pairs of the form (value, mask)
+ * @param upper_cubes the cubes they can be merged with
+ * @param number_of_vars the number of variables of the cubes
+ * @return a tuple of the form (cubes of both sets that were merged, merged cubes)
+ */
+pub(crate) fn merge_adjacent_cubes(
+  cubes: &BTreeSet<(u64, u64)>,
+  upper_cubes: &BTreeSet<(u64, u64)>,
+  number_of_vars: usize,
+) -> MergeStep {
+  let mut merged = HashSet::new();
+  let mut merged_cubes = BTreeSet::new();
+  for (value, mask) in cubes {
+    for position in 0..number_of_vars {
+      let bit = 1 << position;
+      if (mask | value) & bit == 0 && upper_cubes.contains(&(value | bit, *mask)) {
+        merged.insert((*value, *mask));
+        merged.insert((value | bit, *mask));
+        merged_cubes.insert((*value, mask | bit));
+      }
+    }
+  }
+
+  (merged, merged_cubes)
+}
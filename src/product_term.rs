@@ -1,5 +1,6 @@
 use linked_hash_map::LinkedHashMap;
 use literal_value::LiteralValue;
+use minimization_error::MinimizationError;
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
 
@@ -19,19 +20,6 @@ pub struct ProductTerm {
 }
 
 impl ProductTerm {
-  /**
-   * Clones this product term
-   * @return a clone of this product term
-   */
-  pub fn clone(&self) -> ProductTerm {
-    let mut literals = LinkedHashMap::new();
-    for (variable, literal) in &self.literals {
-      literals.insert(String::clone(variable), *literal);
-    }
-
-    ProductTerm { literals }
-  }
-
   /**
    * Creates a new product term with the given literals
    * @param literals a vector of tuples of the form (variable, literal)
@@ -74,6 +62,18 @@ impl ProductTerm {
     self.literals.clone()
   }
 
+  /**
+   * Gets the literal of the given variable
+   * @param variable name of the variable
+   * @return value of the literal; don't care if the variable does not appear in this product term
+   */
+  pub fn get_literal(&self, variable: &str) -> LiteralValue {
+    match self.literals.get(variable) {
+      Some(literal) => *literal,
+      None => LiteralValue::DontCare,
+    }
+  }
+
   /**
    * Adds a given literal
    * @param name name of the literal
@@ -91,6 +91,23 @@ impl ProductTerm {
     self.literals.pop_back().unwrap().1
   }
 
+  /**
+   * Creates a copy of this product term that contains only its true and false literals,
+   * sorted by variable name, so that equal product terms also have equal hashes
+   * @return the normalized product term
+   */
+  pub fn normalized(&self) -> ProductTerm {
+    let mut literals: Vec<(String, LiteralValue)> = self
+      .literals
+      .iter()
+      .filter(|(_, literal)| **literal != LiteralValue::DontCare)
+      .map(|(variable, literal)| (variable.clone(), *literal))
+      .collect();
+    literals.sort_by(|first, second| first.0.cmp(&second.0));
+
+    ProductTerm::new_with_literals(literals)
+  }
+
   /**
    * Merges this product term with another one
    * @param other a product term with which to try merge
@@ -125,6 +142,52 @@ impl ProductTerm {
     Ok(new_product_term)
   }
 
+  /**
+   * Merges this product term with another one that may specify a different set of variables.
+   * The product terms are aligned on the variables specified by both of them: if they have opposite
+   * literals for exactly one of these variables, the result drops that variable and keeps every
+   * other literal specified by either product term (the consensus of the two product terms).
+   * Every minterm covered by the result is covered by this product term or by the other one, so the
+   * result is an implicant of any function containing both. When the supports are equal this is the
+   * usual merge.
+   * @param other a product term with which to try merge
+   * @return the merged product term; None if the product terms have no opposite literal or more than one
+   */
+  pub fn merge_projected(&self, other: &ProductTerm) -> Option<ProductTerm> {
+    let mut opposite_variable = None;
+    for (variable, literal) in &self.literals {
+      let other_literal = other.get_literal(variable);
+      if *literal != LiteralValue::DontCare
+        && other_literal != LiteralValue::DontCare
+        && *literal != other_literal
+      {
+        if opposite_variable.is_some() {
+          return None;
+        }
+        opposite_variable = Some(variable);
+      }
+    }
+    let opposite_variable = opposite_variable?;
+
+    let mut new_product_term = ProductTerm::new();
+    for (variable, literal) in &self.literals {
+      if variable == opposite_variable {
+        new_product_term.add_literal(variable.clone(), LiteralValue::DontCare);
+      } else if *literal == LiteralValue::DontCare {
+        new_product_term.add_literal(variable.clone(), other.get_literal(variable));
+      } else {
+        new_product_term.add_literal(variable.clone(), *literal);
+      }
+    }
+    for (variable, literal) in &other.literals {
+      if !self.literals.contains_key(variable) {
+        new_product_term.add_literal(variable.clone(), *literal);
+      }
+    }
+
+    Some(new_product_term)
+  }
+
   /**
    * Checks if this product term can be merged with a given one
    * @param other a product term to check if it can be merged with this
@@ -152,14 +215,14 @@ impl ProductTerm {
     let mut buffer = "".to_string();
     for (variable, literal) in &self.literals {
       if *literal == LiteralValue::False {
-        if buffer != "" {
-          buffer.push_str(&"&");
+        if !buffer.is_empty() {
+          buffer.push('&');
         }
-        buffer.push_str(&"~");
+        buffer.push('~');
         buffer.push_str(variable);
       } else if *literal == LiteralValue::True {
-        if buffer != "" {
-          buffer.push_str(&"&");
+        if !buffer.is_empty() {
+          buffer.push('&');
         }
         buffer.push_str(variable);
       }
@@ -168,6 +231,58 @@ impl ProductTerm {
     buffer
   }
 
+  /**
+   * Transforms this product term in its positional cube representation
+   * (1 for a true literal, 0 for a false literal and - for a don't care or missing literal)
+   * @param variable_order the variables that appear in the product terms
+   * @return cube representation of this product term
+   */
+  pub fn to_cube_string(&self, variable_order: &[String]) -> String {
+    let mut buffer = String::new();
+    for variable in variable_order {
+      match self.literals.get(variable) {
+        Some(LiteralValue::True) => buffer.push('1'),
+        Some(LiteralValue::False) => buffer.push('0'),
+        _ => buffer.push('-'),
+      }
+    }
+
+    buffer
+  }
+
+  /**
+   * Creates a new product term from its positional cube representation
+   * @param s cube representation containing one of 1, 0 or - for each variable
+   * @param variable_order the variables that appear in the product terms
+   * @return a new product term with a literal for each variable in the order
+   * @throws error if the cube length does not match the variable order or it contains an invalid character
+   */
+  pub fn from_cube_string(
+    s: &str,
+    variable_order: &[String],
+  ) -> Result<ProductTerm, MinimizationError> {
+    let length = s.chars().count();
+    if length != variable_order.len() {
+      return Err(MinimizationError::InvalidLength {
+        expected: variable_order.len(),
+        actual: length,
+      });
+    }
+
+    let mut product_term = ProductTerm::new();
+    for (character, variable) in s.chars().zip(variable_order) {
+      let literal = match character {
+        '1' => LiteralValue::True,
+        '0' => LiteralValue::False,
+        '-' => LiteralValue::DontCare,
+        _ => return Err(MinimizationError::InvalidCharacter(character)),
+      };
+      product_term.add_literal(String::clone(variable), literal);
+    }
+
+    Ok(product_term)
+  }
+
   /**
    * Checks if this product term is a prefix of another
    * @param other product term to check if it contains this product term
@@ -194,7 +309,7 @@ impl ProductTerm {
    */
   pub fn is_prefix_of_any(&self, terms: &HashSet<ProductTerm>) -> bool {
     for product_term in terms {
-      if self.is_prefix_of(&product_term) {
+      if self.is_prefix_of(product_term) {
         return true;
       }
     }
@@ -209,7 +324,7 @@ impl ProductTerm {
    */
   pub fn matches_any(&self, terms: &HashSet<ProductTerm>) -> bool {
     for product_term in terms {
-      if self.matches(&product_term) {
+      if self.matches(product_term) {
         return true;
       }
     }
@@ -225,6 +340,152 @@ impl ProductTerm {
   fn matches(&self, term: &ProductTerm) -> bool {
     self.is_prefix_of(term) && self.literals.len() == term.get_literals().len()
   }
+
+  /**
+   * Checks if this product term covers the given minterm.
+   * The first variable in the order is the most significant bit of the minterm index. Variables
+   * before the last 64 have no bit in the index and are read as false.
+   * @param minterm index of the minterm to check
+   * @param variable_order the variables that appear in the product terms
+   * @return true if every literal of this product term agrees with the minterm; false otherwise
+   */
+  pub fn covers_minterm(&self, minterm: u64, variable_order: &[String]) -> bool {
+    let number_of_vars = variable_order.len();
+    for (position, variable) in variable_order.iter().enumerate() {
+      let bit = minterm
+        .checked_shr((number_of_vars - 1 - position) as u32)
+        .unwrap_or(0)
+        & 1;
+      match self.literals.get(variable) {
+        Some(LiteralValue::True) if bit == 0 => return false,
+        Some(LiteralValue::False) if bit == 1 => return false,
+        _ => {}
+      }
+    }
+
+    true
+  }
+
+  /**
+   * Counts the literals of this product term that are not don't care
+   * @return the number of true and false literals
+   */
+  pub fn literal_count(&self) -> usize {
+    self
+      .literals
+      .values()
+      .filter(|literal| **literal != LiteralValue::DontCare)
+      .count()
+  }
+
+  /**
+   * Transforms this product term in its mask representation.
+   * The first variable in the order is the most significant bit of both masks. Variables before
+   * the last 64 have no bit in the masks and are left out.
+   * @param variable_order the variables that appear in the product terms
+   * @return a tuple of the form (values of the true literals, positions of the don't care literals)
+   */
+  pub fn to_cube_masks(&self, variable_order: &[String]) -> (u64, u64) {
+    let number_of_vars = variable_order.len();
+    let mut value = 0;
+    let mut mask = 0;
+    for (position, variable) in variable_order.iter().enumerate() {
+      let bit = match 1u64.checked_shl((number_of_vars - 1 - position) as u32) {
+        Some(bit) => bit,
+        None => continue,
+      };
+      match self.get_literal(variable) {
+        LiteralValue::True => value |= bit,
+        LiteralValue::False => {}
+        LiteralValue::DontCare => mask |= bit,
+      }
+    }
+
+    (value, mask)
+  }
+
+  /**
+   * Creates a new product term from its mask representation. Variables before the last 64 have no
+   * bit in the masks and get false literals.
+   * @param value values of the true literals
+   * @param mask positions of the don't care literals
+   * @param variable_order the variables that appear in the product terms
+   * @return a new product term with a literal for each variable in the order
+   */
+  pub fn from_cube_masks(value: u64, mask: u64, variable_order: &[String]) -> ProductTerm {
+    let number_of_vars = variable_order.len();
+    let mut product_term = ProductTerm::new();
+    for (position, variable) in variable_order.iter().enumerate() {
+      let bit = 1u64
+        .checked_shl((number_of_vars - 1 - position) as u32)
+        .unwrap_or(0);
+      let literal = if mask & bit != 0 {
+        LiteralValue::DontCare
+      } else if value & bit != 0 {
+        LiteralValue::True
+      } else {
+        LiteralValue::False
+      };
+      product_term.add_literal(String::clone(variable), literal);
+    }
+
+    product_term
+  }
+
+  /**
+   * Creates a new product term that covers only the given minterm
+   * @param minterm index of the minterm
+   * @param variable_order the variables that appear in the product terms
+   * @return a new min term with a literal for each variable in the order
+   */
+  pub fn from_minterm(minterm: u64, variable_order: &[String]) -> ProductTerm {
+    ProductTerm::from_cube_masks(minterm, 0, variable_order)
+  }
+
+  /**
+   * Expands this product term in the minterms it covers
+   * @param variable_order the variables that appear in the product terms
+   * @return the indices of the covered minterms, in ascending order
+   */
+  pub fn minterms(&self, variable_order: &[String]) -> Vec<u64> {
+    let (value, mask) = self.to_cube_masks(variable_order);
+    let mut minterms = Vec::new();
+    let mut free_bits = mask;
+    loop {
+      minterms.push(value | free_bits);
+      if free_bits == 0 {
+        break;
+      }
+      free_bits = (free_bits - 1) & mask;
+    }
+    minterms.reverse();
+
+    minterms
+  }
+}
+
+impl Clone for ProductTerm {
+  /**
+   * Clones this product term
+   * @return a clone of this product term
+   */
+  fn clone(&self) -> ProductTerm {
+    let mut literals = LinkedHashMap::new();
+    for (variable, literal) in &self.literals {
+      literals.insert(String::clone(variable), *literal);
+    }
+
+    ProductTerm { literals }
+  }
+}
+
+impl Default for ProductTerm {
+  /**
+   * Creates a new empty product term
+   */
+  fn default() -> ProductTerm {
+    ProductTerm::new()
+  }
 }
 
 impl PartialEq<ProductTerm> for ProductTerm {
@@ -277,7 +538,7 @@ impl Hash for ProductTerm {
 
 impl Eq for ProductTerm {}
 
-impl<'a> std::fmt::Display for ProductTerm {
+impl std::fmt::Display for ProductTerm {
   /**
    * Method that prints this product term's string representation
    */
@@ -286,10 +547,26 @@ impl<'a> std::fmt::Display for ProductTerm {
   }
 }
 
+/**
+ * Builds a set of product terms from their cube representations, for the tests of the modules
+ * that work on cubes
+ * @param cubes the cube representations of the product terms
+ * @param variable_order the variables that appear in the product terms
+ * @return set containing a product term for each cube
+ */
+#[cfg(test)]
+pub fn terms_from_cubes(cubes: &[&str], variable_order: &[String]) -> HashSet<ProductTerm> {
+  cubes
+    .iter()
+    .map(|cube| ProductTerm::from_cube_string(cube, variable_order).unwrap())
+    .collect()
+}
+
 /**
  * Module for tests regarding the Product Term struct and its methods
  */
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod product_term_tests {
   use super::*;
 
@@ -736,4 +1013,72 @@ mod product_term_tests {
 
     assert!(!term.is_prefix_of_any(&set));
   }
+
+  #[test]
+  fn test_cube_string_01() {
+    let variable_order = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+    let term = ProductTerm::new_with_literals(vec![
+      ("A".to_string(), LiteralValue::True),
+      ("B".to_string(), LiteralValue::DontCare),
+      ("C".to_string(), LiteralValue::False),
+    ]);
+
+    let cube = term.to_cube_string(&variable_order);
+
+    assert_eq!(cube, "1-0");
+    assert_eq!(
+      ProductTerm::from_cube_string(&cube, &variable_order).unwrap(),
+      term
+    );
+  }
+
+  #[test]
+  fn test_cube_string_02_fail() {
+    let variable_order = vec!["A".to_string(), "B".to_string()];
+
+    assert_eq!(
+      ProductTerm::from_cube_string("1-0", &variable_order),
+      Err(MinimizationError::InvalidLength {
+        expected: 2,
+        actual: 3
+      })
+    );
+    assert_eq!(
+      ProductTerm::from_cube_string("1x", &variable_order),
+      Err(MinimizationError::InvalidCharacter('x'))
+    );
+  }
+
+  #[test]
+  fn test_merge_projected_01() {
+    let variable_order = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+    let term = ProductTerm::from_cube_string("11-", &variable_order).unwrap();
+    let other = ProductTerm::from_cube_string("0-1", &variable_order).unwrap();
+
+    let merged = term.merge_projected(&other).unwrap();
+
+    assert_eq!(
+      merged,
+      ProductTerm::from_cube_string("-11", &variable_order).unwrap()
+    );
+    for minterm in merged.minterms(&variable_order) {
+      assert!(
+        term.covers_minterm(minterm, &variable_order)
+          || other.covers_minterm(minterm, &variable_order)
+      );
+    }
+  }
+
+  #[test]
+  fn test_merge_projected_02_fail() {
+    let variable_order = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+    let term = ProductTerm::from_cube_string("11-", &variable_order).unwrap();
+
+    assert!(term
+      .merge_projected(&ProductTerm::from_cube_string("00-", &variable_order).unwrap())
+      .is_none());
+    assert!(term
+      .merge_projected(&ProductTerm::from_cube_string("1-1", &variable_order).unwrap())
+      .is_none());
+  }
 }
@@ -0,0 +1,227 @@
+use literal_value::LiteralValue;
+use product_term::ProductTerm;
+use reduced_node::ReducedNode;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/**
+ * Struct that builds reduced ordered decision diagrams sharing a single unique table, so every
+ * function built over the same variable order is represented by exactly one node. Once built,
+ * checking two such functions for equivalence or a function for tautology is a pointer comparison.
+ */
+pub struct ReducedManager {
+  terminals: (Rc<ReducedNode>, Rc<ReducedNode>),
+  unique_table: HashMap<(String, usize, usize), Rc<ReducedNode>>,
+}
+
+impl ReducedManager {
+  /**
+   * Creates a new manager
+   * @return a new manager containing only the two terminals
+   */
+  pub fn new() -> ReducedManager {
+    ReducedManager {
+      terminals: (
+        Rc::new(ReducedNode::Terminal(false)),
+        Rc::new(ReducedNode::Terminal(true)),
+      ),
+      unique_table: HashMap::new(),
+    }
+  }
+
+  /**
+   * Builds the reduced diagram of the function covered by the given terms, reusing the nodes that
+   * were already built by this manager. Every cofactor is built once, even if it is reached by
+   * several paths.
+   * @param terms product terms covering the function
+   * @param variable_order the variables that appear in the product terms, in decision order
+   * @return the root node of the reduced diagram
+   */
+  pub fn build(
+    &mut self,
+    terms: &HashSet<ProductTerm>,
+    variable_order: &[String],
+  ) -> Rc<ReducedNode> {
+    let term_list: Vec<&ProductTerm> = terms.iter().collect();
+    let mut computed_table = HashMap::new();
+
+    self.build_cofactor(
+      &term_list,
+      (0..term_list.len()).collect(),
+      variable_order,
+      &mut computed_table,
+    )
+  }
+
+  /**
+   * Checks if two diagrams built by this manager over the same variable order represent the same
+   * function
+   * @param first the root node of the first diagram
+   * @param second the root node of the second diagram
+   * @return true if the functions are equivalent; false otherwise
+   */
+  pub fn are_equivalent(&self, first: &Rc<ReducedNode>, second: &Rc<ReducedNode>) -> bool {
+    Rc::ptr_eq(first, second)
+  }
+
+  /**
+   * Checks if a diagram built by this manager represents the constant true function
+   * @param node the root node of the diagram
+   * @return true if the node is the true terminal of this manager; false otherwise
+   */
+  pub fn is_tautology(&self, node: &Rc<ReducedNode>) -> bool {
+    Rc::ptr_eq(node, &self.terminals.1)
+  }
+
+  /**
+   * Counts the decision nodes built by this manager
+   * @return the number of entries of the unique table
+   */
+  pub fn len(&self) -> usize {
+    self.unique_table.len()
+  }
+
+  /**
+   * Checks if this manager has not built any decision node yet
+   * @return true if the unique table is empty; false otherwise
+   */
+  pub fn is_empty(&self) -> bool {
+    self.unique_table.is_empty()
+  }
+
+  /**
+   * Builds the reduced diagram of the cofactor covered by the given terms
+   * @param terms all the product terms of the function
+   * @param indices the indices of the product terms covering the current cofactor
+   * @param variable_order the variables that were not decided yet
+   * @param computed_table the already built cofactors, keyed by the number of undecided variables and their product terms
+   * @return the node representing the current cofactor
+   */
+  fn build_cofactor(
+    &mut self,
+    terms: &[&ProductTerm],
+    indices: Vec<usize>,
+    variable_order: &[String],
+    computed_table: &mut HashMap<(usize, Vec<usize>), Rc<ReducedNode>>,
+  ) -> Rc<ReducedNode> {
+    if indices.is_empty() {
+      return Rc::clone(&self.terminals.0);
+    }
+    if indices.iter().any(|index| {
+      variable_order
+        .iter()
+        .all(|variable| terms[*index].get_literal(variable) == LiteralValue::DontCare)
+    }) {
+      return Rc::clone(&self.terminals.1);
+    }
+    let key = (variable_order.len(), indices);
+    if let Some(node) = computed_table.get(&key) {
+      return Rc::clone(node);
+    }
+
+    let variable = &variable_order[0];
+    let low_indices: Vec<usize> = key
+      .1
+      .iter()
+      .filter(|index| terms[**index].get_literal(variable) != LiteralValue::True)
+      .cloned()
+      .collect();
+    let high_indices: Vec<usize> = key
+      .1
+      .iter()
+      .filter(|index| terms[**index].get_literal(variable) != LiteralValue::False)
+      .cloned()
+      .collect();
+    let low = self.build_cofactor(terms, low_indices, &variable_order[1..], computed_table);
+    let high = self.build_cofactor(terms, high_indices, &variable_order[1..], computed_table);
+
+    let node = if Rc::ptr_eq(&low, &high) {
+      low
+    } else {
+      let unique_key = (
+        String::clone(variable),
+        Rc::as_ptr(&low) as usize,
+        Rc::as_ptr(&high) as usize,
+      );
+      let node = self.unique_table.entry(unique_key).or_insert_with(|| {
+        Rc::new(ReducedNode::Decision {
+          variable: String::clone(variable),
+          low,
+          high,
+        })
+      });
+      Rc::clone(node)
+    };
+    computed_table.insert(key, Rc::clone(&node));
+
+    node
+  }
+}
+
+impl Default for ReducedManager {
+  /**
+   * Creates a new manager containing only the two terminals
+   */
+  fn default() -> ReducedManager {
+    ReducedManager::new()
+  }
+}
+
+/**
+ * Module for tests regarding the reduced manager
+ */
+#[cfg(test)]
+mod reduced_manager_tests {
+  use super::*;
+  use product_term::terms_from_cubes;
+
+  #[test]
+  fn test_build_01() {
+    let variable_order = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+    let mut manager = ReducedManager::new();
+
+    let root = manager.build(
+      &terms_from_cubes(&["0-1", "11-", "-11"], &variable_order),
+      &variable_order,
+    );
+    let nodes = manager.len();
+    let equivalent_root = manager.build(
+      &terms_from_cubes(&["0-1", "11-"], &variable_order),
+      &variable_order,
+    );
+    assert_eq!(manager.len(), nodes);
+    let other_root = manager.build(
+      &terms_from_cubes(&["11-"], &variable_order),
+      &variable_order,
+    );
+
+    assert!(manager.are_equivalent(&root, &equivalent_root));
+    assert!(!manager.are_equivalent(&root, &other_root));
+    let tautology = manager.build(
+      &terms_from_cubes(&["0--", "1--"], &variable_order),
+      &variable_order,
+    );
+    assert!(manager.is_tautology(&tautology));
+  }
+
+  #[test]
+  fn test_build_02() {
+    let variable_order: Vec<String> = (0..60).map(|index| format!("x{}", index)).collect();
+    let cubes: Vec<String> = (0..30)
+      .map(|pair| {
+        (0..60)
+          .map(|position| if position / 2 == pair { '1' } else { '-' })
+          .collect()
+      })
+      .collect();
+    let cube_refs: Vec<&str> = cubes.iter().map(|cube| cube.as_str()).collect();
+    let mut manager = ReducedManager::new();
+
+    let root = manager.build(
+      &terms_from_cubes(&cube_refs, &variable_order),
+      &variable_order,
+    );
+
+    assert_eq!(root.node_count(), 62);
+  }
+}
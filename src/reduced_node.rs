@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/**
+ * Enum that represents a node of a reduced ordered decision diagram.
+ * Isomorphic subtrees are shared and decisions whose branches are equal are skipped,
+ * so two equivalent functions built over the same variable order yield structurally equal diagrams.
+ * Comparing two diagrams walks them, short-circuiting on shared nodes; diagrams built by the same
+ * ReducedManager can be compared by their root pointers instead.
+ */
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ReducedNode {
+  Terminal(bool),
+  Decision {
+    variable: String,
+    low: Rc<ReducedNode>,
+    high: Rc<ReducedNode>,
+  },
+}
+
+impl ReducedNode {
+  /**
+   * Checks if this diagram represents the constant true function
+   * @return true if this node is the true terminal; false otherwise
+   */
+  pub fn is_tautology(&self) -> bool {
+    *self == ReducedNode::Terminal(true)
+  }
+
+  /**
+   * Checks if this diagram represents the constant false function
+   * @return true if this node is the false terminal; false otherwise
+   */
+  pub fn is_contradiction(&self) -> bool {
+    *self == ReducedNode::Terminal(false)
+  }
+
+  /**
+   * Counts the distinct nodes of this diagram, shared nodes being counted once
+   * @return the number of distinct nodes, terminals included
+   */
+  pub fn node_count(&self) -> usize {
+    let mut visited = HashSet::new();
+    self.collect_nodes(&mut visited);
+    visited.len()
+  }
+
+  /**
+   * Collects the addresses of the nodes reachable from this node
+   * @param visited set to add the addresses of the reachable nodes to
+   */
+  fn collect_nodes(&self, visited: &mut HashSet<*const ReducedNode>) {
+    if !visited.insert(self as *const ReducedNode) {
+      return;
+    }
+
+    if let ReducedNode::Decision { low, high, .. } = self {
+      low.collect_nodes(visited);
+      high.collect_nodes(visited);
+    }
+  }
+}
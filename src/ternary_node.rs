@@ -1,3 +1,6 @@
+use byte_encoding::{read_bytes, read_string, read_u32, write_string};
+use literal_value::LiteralValue;
+use minimization_error::MinimizationError;
 use product_term::ProductTerm;
 use std::hash::{Hash, Hasher};
 use std::option::Option;
@@ -12,26 +15,6 @@ pub struct TernaryNode {
 }
 
 impl TernaryNode {
-    /**
-     * Clones the given node
-     * @param node node to be cloned
-     * @return clone of the given node
-     */
-    pub fn clone(node: &TernaryNode) -> TernaryNode {
-        TernaryNode {
-            variable: if node.variable.is_some() {
-                Some(String::clone(node.variable.as_ref().unwrap()))
-            } else {
-                None
-            },
-            built_term: if node.built_term.is_some() {
-                Some(node.built_term.as_ref().unwrap().clone())
-            } else {
-                None
-            },
-        }
-    }
-
     /**
      * Creates a new node
      * @return a new empty node
@@ -83,9 +66,113 @@ impl TernaryNode {
     pub fn get_term(&self) -> Option<&ProductTerm> {
         self.built_term.as_ref()
     }
+
+    /**
+     * Serializes this node to a compact binary format: a flags byte telling which of the variable
+     * and the product term are present, the variable as a length prefixed string, then the number
+     * of literals of the product term, their variables as length prefixed strings and their values
+     * packed four per byte. Lengths are little endian 32-bit integers and strings are UTF-8.
+     * Since the product term of a node is the path from the root, the node keeps its place in the tree.
+     * @return the bytes encoding this node
+     */
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let flags = self.variable.is_some() as u8 | (self.built_term.is_some() as u8) << 1;
+        bytes.push(flags);
+
+        if let Some(variable) = &self.variable {
+            write_string(&mut bytes, variable);
+        }
+        if let Some(built_term) = &self.built_term {
+            let literals = built_term.get_literals();
+            bytes.extend_from_slice(&(literals.len() as u32).to_le_bytes());
+            for variable in literals.keys() {
+                write_string(&mut bytes, variable);
+            }
+            let mut packed = vec![0u8; literals.len().div_ceil(4)];
+            for (index, literal) in literals.values().enumerate() {
+                packed[index / 4] |= (*literal as u8) << (index % 4 * 2);
+            }
+            bytes.extend_from_slice(&packed);
+        }
+
+        bytes
+    }
+
+    /**
+     * Deserializes a node written by to_bytes
+     * @param bytes the bytes encoding the node
+     * @return the decoded node
+     * @throws error if the bytes are truncated, contain invalid values or have trailing data
+     */
+    pub fn from_bytes(bytes: &[u8]) -> Result<TernaryNode, MinimizationError> {
+        let mut position = 0;
+        let flags = read_bytes(bytes, &mut position, 1)?[0];
+        if flags > 3 {
+            return Err(MinimizationError::InvalidEncoding { position: 0 });
+        }
+
+        let mut node = TernaryNode::new();
+        if flags & 1 != 0 {
+            node.set_variable(read_string(bytes, &mut position)?);
+        }
+        if flags & 2 != 0 {
+            let count = read_u32(bytes, &mut position)? as usize;
+            let mut variables = Vec::new();
+            for _ in 0..count {
+                variables.push(read_string(bytes, &mut position)?);
+            }
+            let packed_position = position;
+            let packed = read_bytes(bytes, &mut position, count.div_ceil(4))?;
+
+            let mut term = ProductTerm::new();
+            for (index, variable) in variables.into_iter().enumerate() {
+                let literal = match packed[index / 4] >> (index % 4 * 2) & 3 {
+                    0 => LiteralValue::False,
+                    1 => LiteralValue::True,
+                    2 => LiteralValue::DontCare,
+                    _ => {
+                        return Err(MinimizationError::InvalidEncoding {
+                            position: packed_position + index / 4,
+                        })
+                    }
+                };
+                term.add_literal(variable, literal);
+            }
+            node.set_term(term);
+        }
+
+        if position != bytes.len() {
+            return Err(MinimizationError::InvalidEncoding { position });
+        }
+
+        Ok(node)
+    }
+}
+
+impl Clone for TernaryNode {
+    /**
+     * Clones this node
+     * @return clone of this node
+     */
+    fn clone(&self) -> TernaryNode {
+        TernaryNode {
+            variable: self.variable.clone(),
+            built_term: self.built_term.clone(),
+        }
+    }
+}
+
+impl Default for TernaryNode {
+    /**
+     * Creates a new empty node
+     */
+    fn default() -> TernaryNode {
+        TernaryNode::new()
+    }
 }
 
-impl<'a> PartialEq<TernaryNode> for TernaryNode {
+impl PartialEq<TernaryNode> for TernaryNode {
     /**
      * Method to test equality between this ternary node and another
      * @param other the other ternary node to compare to
@@ -100,7 +187,7 @@ impl<'a> PartialEq<TernaryNode> for TernaryNode {
             } else {
                 return false;
             }
-        } else if let Some(_) = &other.variable {
+        } else if other.variable.is_some() {
             return false;
         }
 
@@ -135,13 +222,13 @@ impl std::fmt::Debug for TernaryNode {
         write!(
             f,
             "(var={}, term={})",
-            if self.variable.is_some() {
-                self.variable.as_ref().unwrap()
+            if let Some(variable) = &self.variable {
+                variable
             } else {
                 ""
             },
-            if self.built_term.is_some() {
-                self.built_term.as_ref().unwrap()
+            if let Some(built_term) = &self.built_term {
+                built_term
             } else {
                 &dummy_term
             }